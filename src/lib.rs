@@ -15,6 +15,9 @@ pub struct Instruction {
     pub program: String,
     // The data contained from invoking this instruction.
     pub data: Vec<u8>,
+    // The ordered pubkeys of the accounts this instruction touches, resolved from the
+    // instruction's account index list against the transaction's account_keys.
+    pub accounts: Vec<String>,
     // If this is an inner instruction, we should depend on this
     pub parent_index: i16,
     // The time this log was created in our time
@@ -59,9 +62,14 @@ pub struct InstructionSet {
 
 /// Derive a simple, singular function that 'decompiles' support program instruction invocations
 /// into a database and json-compatible format based on Solana FM's instruction properties.
+///
+/// When `capture_unparsed` is set, an instruction that belongs to an unsupported program or
+/// that fails to decode is still emitted as a reserved "unparsed" `InstructionSet` instead of
+/// being dropped, so a transaction can be fully accounted for even when coverage is incomplete.
 pub async fn process(
     instructions: Vec<Instruction>,
-    og_instructions: Option<Vec<CompiledInstruction>>
+    og_instructions: Option<Vec<CompiledInstruction>>,
+    capture_unparsed: bool,
 ) -> Vec<InstructionSet> {
     let instruction_jobs: Vec<_> = instructions.into_iter()
         .map(|instruction| {
@@ -72,7 +80,20 @@ pub async fn process(
             };
 
             spawn(async move {
-                match instruction.program.as_str() {
+                let mut is_unsupported_program = false;
+                let mut is_missing_context = false;
+                let mut parse_error_detail: Option<String> = None;
+
+                // Only paid when the "unparsed" capture mode is actually enabled, since this
+                // would otherwise be a deep clone of every instruction's data/accounts on the
+                // hot path for a feature the caller hasn't opted into.
+                let unparsed_fallback = if capture_unparsed {
+                    Some(instruction.clone())
+                } else {
+                    None
+                };
+
+                let result = match instruction.program.as_str() {
                     programs::native_associated_token_account::PROGRAM_ADDRESS => {
                         crate::programs::native_associated_token_account::fragment_instruction(
                             instruction).await
@@ -87,12 +108,22 @@ pub async fn process(
                     },
                     programs::bpf_loader::PROGRAM_ADDRESS |
                     programs::bpf_loader::PROGRAM_ADDRESS_2 => {
-                        crate::programs::bpf_loader::fragment_instruction(instruction)
-                            .await
+                        match crate::programs::bpf_loader::fragment_instruction(instruction).await {
+                            Ok(instruction_set) => Some(instruction_set),
+                            Err(err) => {
+                                parse_error_detail = Some(err);
+                                None
+                            }
+                        }
                     },
                     programs::bpf_loader_upgradeable::PROGRAM_ADDRESS => {
-                        crate::programs::bpf_loader_upgradeable::fragment_instruction(instruction)
-                            .await
+                        match crate::programs::bpf_loader_upgradeable::fragment_instruction(instruction).await {
+                            Ok(instruction_set) => Some(instruction_set),
+                            Err(err) => {
+                                parse_error_detail = Some(err);
+                                None
+                            }
+                        }
                     }
                     programs::native_secp256k1::PROGRAM_ADDRESS => {
                         if let Some(og_instructs) = ogi {
@@ -100,6 +131,9 @@ pub async fn process(
                                                                                     og_instructs.as_slice())
                                 .await
                         } else {
+                            // Missing optional context, not a decode failure - keep it out of
+                            // the parse-failure bucket so the two don't get conflated downstream.
+                            is_missing_context = true;
                             None
                         }
                     }
@@ -141,8 +175,29 @@ pub async fn process(
                         info!("Looks like this program ({}) is an unsupported one.",
                             instruction.program.to_string());
 
+                        is_unsupported_program = true;
                         None
                     }
+                };
+
+                match result {
+                    Some(instruction_set) => Some(instruction_set),
+                    None if capture_unparsed => {
+                        let error_category = if is_unsupported_program {
+                            "unsupported_program".to_string()
+                        } else if is_missing_context {
+                            "missing_context".to_string()
+                        } else if let Some(detail) = parse_error_detail {
+                            detail
+                        } else {
+                            "parse_failed".to_string()
+                        };
+
+                        unparsed_fallback.map(|fallback| {
+                            programs::build_unparsed_instruction_set(&fallback, error_category.as_str())
+                        })
+                    }
+                    None => None,
                 }
             })
         })
@@ -163,8 +218,85 @@ pub async fn process(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    const SECP256K1_PROGRAM_ADDRESS: &str = "KeccakSecp256k11111111111111111111111111111";
+    const UNSUPPORTED_PROGRAM_ADDRESS: &str = "Unsupported1111111111111111111111111111111";
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    fn test_instruction(program: &str, data: Vec<u8>) -> Instruction {
+        Instruction {
+            tx_instruction_id: 0,
+            transaction_hash: "test_tx".to_string(),
+            program: program.to_string(),
+            data,
+            accounts: vec![],
+            parent_index: -1,
+            timestamp: 0,
+        }
+    }
+
+    fn error_category(instruction_set: &InstructionSet) -> &str {
+        instruction_set.properties.iter()
+            .find(|p| p.key == "error_category")
+            .unwrap_or_else(|| panic!("missing error_category property"))
+            .value.as_str()
+    }
+
+    #[tokio::test]
+    async fn capture_unparsed_emits_unsupported_program_category() {
+        let instruction = test_instruction(UNSUPPORTED_PROGRAM_ADDRESS, vec![]);
+
+        let instruction_sets = process(vec![instruction], None, true).await;
+
+        assert_eq!(instruction_sets.len(), 1);
+        assert_eq!(instruction_sets[0].function.function_name, "unparsed");
+        assert_eq!(error_category(&instruction_sets[0]), "unsupported_program");
+    }
+
+    #[tokio::test]
+    async fn capture_unparsed_emits_real_bincode_error_for_bpf_loader() {
+        let instruction = test_instruction(programs::bpf_loader::PROGRAM_ADDRESS, vec![0xFF, 0xFF, 0xFF]);
+
+        let instruction_sets = process(vec![instruction], None, true).await;
+
+        assert_eq!(instruction_sets.len(), 1);
+        assert!(error_category(&instruction_sets[0]).starts_with("bincode_deserialize_failed"));
+    }
+
+    #[tokio::test]
+    async fn capture_unparsed_emits_real_bincode_error_for_bpf_loader_upgradeable() {
+        let instruction = test_instruction(
+            programs::bpf_loader_upgradeable::PROGRAM_ADDRESS,
+            vec![0xFF, 0xFF, 0xFF],
+        );
+
+        let instruction_sets = process(vec![instruction], None, true).await;
+
+        assert_eq!(instruction_sets.len(), 1);
+        assert!(error_category(&instruction_sets[0]).starts_with("bincode_deserialize_failed"));
+    }
+
+    #[tokio::test]
+    async fn capture_unparsed_emits_missing_context_for_secp256k1_without_og_instructions() {
+        let instruction = test_instruction(SECP256K1_PROGRAM_ADDRESS, vec![]);
+
+        let instruction_sets = process(vec![instruction], None, true).await;
+
+        assert_eq!(instruction_sets.len(), 1);
+        assert_eq!(error_category(&instruction_sets[0]), "missing_context");
+    }
+
+    #[tokio::test]
+    async fn capture_unparsed_disabled_drops_failures_silently() {
+        let instruction = test_instruction(UNSUPPORTED_PROGRAM_ADDRESS, vec![]);
+
+        let instruction_sets = process(vec![instruction], None, false).await;
+
+        assert!(instruction_sets.is_empty());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,162 @@
+use solana_sdk::loader_instruction::LoaderInstruction;
+use tracing::error;
+
+use crate::programs::{account_at, encode_bytes};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "BPFLoader1111111111111111111111111111111111";
+pub const PROGRAM_ADDRESS_2: &str = "BPFLoader2111111111111111111111111111111111";
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+/// On failure, returns the deserialize error's category so callers (like `process`'s
+/// "unparsed" capture mode) can tell a genuine decode failure apart from an unsupported program.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Result<InstructionSet, String> {
+    let bpf_loader_dr =
+        bincode::deserialize::<LoaderInstruction>(instruction.data.as_slice());
+
+    return match bpf_loader_dr {
+        Ok(bpf_loader_i) => {
+            match bpf_loader_i {
+                LoaderInstruction::Write { offset, bytes } => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "write".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "account".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "offset".to_string(),
+                                value: offset.to_string(),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "bytes".to_string(),
+                                value: encode_bytes(&bytes),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                LoaderInstruction::Finalize => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "finalize".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "account".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "finalize".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+            }
+        }
+        Err(ref deserialize_err) => {
+            // If the instruction parsing is failing, bail out
+            error!("[spi-wrapper/bpf_loader] Attempt to parse instruction from \
+                program {} failed as the instruction data could not be deserialized: {}.",
+                instruction.program, deserialize_err);
+
+            Err(format!("bincode_deserialize_failed: {}", deserialize_err))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_instruction(data: Vec<u8>, accounts: Vec<&str>) -> Instruction {
+        Instruction {
+            tx_instruction_id: 0,
+            transaction_hash: "test_tx".to_string(),
+            program: PROGRAM_ADDRESS.to_string(),
+            data,
+            accounts: accounts.into_iter().map(|a| a.to_string()).collect(),
+            parent_index: -1,
+            timestamp: 0,
+        }
+    }
+
+    fn property<'a>(instruction_set: &'a InstructionSet, key: &str) -> &'a str {
+        instruction_set.properties.iter()
+            .find(|p| p.key == key)
+            .unwrap_or_else(|| panic!("missing property {}", key))
+            .value.as_str()
+    }
+
+    #[tokio::test]
+    async fn write_decodes_offset_and_base64_bytes() {
+        let data = bincode::serialize(&LoaderInstruction::Write {
+            offset: 7,
+            bytes: vec![10, 20, 30],
+        }).unwrap();
+        let instruction = test_instruction(data, vec!["account_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "write");
+        assert_eq!(property(&instruction_set, "account"), "account_pubkey");
+        assert_eq!(property(&instruction_set, "offset"), "7");
+        assert_eq!(property(&instruction_set, "bytes"), base64::encode([10, 20, 30]));
+    }
+
+    #[tokio::test]
+    async fn finalize_decodes_the_finalized_account() {
+        let data = bincode::serialize(&LoaderInstruction::Finalize).unwrap();
+        let instruction = test_instruction(data, vec!["account_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "finalize");
+        assert_eq!(property(&instruction_set, "account"), "account_pubkey");
+    }
+
+    #[tokio::test]
+    async fn truncated_data_returns_err_with_deserialize_failure_detail() {
+        let instruction = test_instruction(vec![0xFF, 0xFF, 0xFF], vec![]);
+
+        let result = fragment_instruction(instruction).await;
+
+        let err = result.expect_err("truncated instruction data should fail to decode");
+        assert!(err.starts_with("bincode_deserialize_failed"));
+    }
+}
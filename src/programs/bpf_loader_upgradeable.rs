@@ -1,8 +1,10 @@
 use solana_account_decoder::parse_bpf_loader::{
     parse_bpf_upgradeable_loader, BpfUpgradeableLoaderAccountType,
 };
+use solana_sdk::loader_upgradeable_instruction::UpgradeableLoaderInstruction;
 use tracing::error;
 
+use crate::programs::{account_at, encode_bytes};
 use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
 use solana_account_decoder::parse_account_data::{ParseAccountError, ParsableAccount};
 
@@ -15,6 +17,405 @@ pub const PROGRAM_ADDRESS: &str = "BPFLoaderUpgradeab1e11111111111111111111111";
 pub async fn fragment_instruction(
     // The instruction
     instruction: Instruction,
+) -> Result<InstructionSet, String> {
+    let bpf_loader_upgradeable_dr =
+        bincode::deserialize::<UpgradeableLoaderInstruction>(instruction.data.as_slice());
+
+    return match bpf_loader_upgradeable_dr {
+        Ok(bpf_loader_upgradeable_i) => {
+            match bpf_loader_upgradeable_i {
+                UpgradeableLoaderInstruction::InitializeBuffer => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize_buffer".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buffer".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "initialize_buffer".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "initialize_buffer".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::Write { offset, bytes } => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "write".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buffer".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "offset".to_string(),
+                                value: offset.to_string(),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "bytes".to_string(),
+                                value: encode_bytes(&bytes),
+                                parent_key: "write".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::DeployWithMaxDataLen { max_data_len } => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deploy_with_max_data_len".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "payer".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program_data".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program".to_string(),
+                                value: account_at(&instruction, 2),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buffer".to_string(),
+                                value: account_at(&instruction, 3),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 7),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_data_len".to_string(),
+                                value: max_data_len.to_string(),
+                                parent_key: "deploy_with_max_data_len".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::Upgrade => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "upgrade".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program_data".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "upgrade".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "upgrade".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buffer".to_string(),
+                                value: account_at(&instruction, 2),
+                                parent_key: "upgrade".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 6),
+                                parent_key: "upgrade".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::SetAuthority => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set_authority".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "account".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "set_authority".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "set_authority".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_authority".to_string(),
+                                value: account_at(&instruction, 2),
+                                parent_key: "set_authority".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::SetAuthorityChecked => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set_authority_checked".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "account".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "set_authority_checked".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "set_authority_checked".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_authority".to_string(),
+                                value: account_at(&instruction, 2),
+                                parent_key: "set_authority_checked".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::Close => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "close".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "account".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "close".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "recipient".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "close".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority".to_string(),
+                                value: account_at(&instruction, 2),
+                                parent_key: "close".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+                UpgradeableLoaderInstruction::ExtendProgram { additional_bytes } => {
+                    Ok(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "extend_program".to_string(),
+                            timestamp: instruction.timestamp.clone()
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program_data".to_string(),
+                                value: account_at(&instruction, 0),
+                                parent_key: "extend_program".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program".to_string(),
+                                value: account_at(&instruction, 1),
+                                parent_key: "extend_program".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "payer".to_string(),
+                                value: account_at(&instruction, 3),
+                                parent_key: "extend_program".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "additional_bytes".to_string(),
+                                value: additional_bytes.to_string(),
+                                parent_key: "extend_program".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ]
+                    })
+                }
+            }
+        }
+        Err(ref deserialize_err) => {
+            // If the instruction parsing is failing, bail out
+            error!("[spi-wrapper/bpf_loader_upgradeable] Attempt to parse instruction from \
+                program {} failed as the instruction data could not be deserialized: {}.",
+                instruction.program, deserialize_err);
+
+            Err(format!("bincode_deserialize_failed: {}", deserialize_err))
+        }
+    }
+}
+
+/// Extracts the contents of an *account's* state (as opposed to an invoked instruction) into
+/// small bits and pieces, or what we would call, instruction_properties. Useful for callers
+/// that pass the raw data of a Buffer/Program/ProgramData account rather than instruction data.
+pub async fn fragment_account_state(
+    // The instruction, whose `data` is actually the account's raw state in this path.
+    instruction: Instruction,
 ) -> Option<InstructionSet> {
     let bpf_loader_upgradeable_dr =
         parse_bpf_upgradeable_loader(instruction.data.as_slice());
@@ -66,7 +467,7 @@ pub async fn fragment_instruction(
                                 transaction_hash: instruction.transaction_hash.clone(),
                                 parent_index: instruction.parent_index.clone(),
                                 key: "data".to_string(),
-                                value: serde_json::to_string(&buffer.data).unwrap().to_string(),
+                                value: encode_bytes(&buffer.data),
                                 parent_key: "buffer".to_string(),
                                 timestamp: instruction.timestamp.clone(),
                             },
@@ -125,7 +526,7 @@ pub async fn fragment_instruction(
                                 transaction_hash: instruction.transaction_hash.clone(),
                                 parent_index: instruction.parent_index.clone(),
                                 key: "data".to_string(),
-                                value: serde_json::to_string(&program_data.data).unwrap().to_string(),
+                                value: encode_bytes(&program_data.data),
                                 parent_key: "program_data".to_string(),
                                 timestamp: instruction.timestamp.clone(),
                             },
@@ -183,4 +584,176 @@ pub async fn fragment_instruction(
             None
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_instruction(data: Vec<u8>, accounts: Vec<&str>) -> Instruction {
+        Instruction {
+            tx_instruction_id: 0,
+            transaction_hash: "test_tx".to_string(),
+            program: PROGRAM_ADDRESS.to_string(),
+            data,
+            accounts: accounts.into_iter().map(|a| a.to_string()).collect(),
+            parent_index: -1,
+            timestamp: 0,
+        }
+    }
+
+    fn property<'a>(instruction_set: &'a InstructionSet, key: &str) -> &'a str {
+        instruction_set.properties.iter()
+            .find(|p| p.key == key)
+            .unwrap_or_else(|| panic!("missing property {}", key))
+            .value.as_str()
+    }
+
+    #[tokio::test]
+    async fn initialize_buffer_decodes_buffer_and_authority() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::InitializeBuffer).unwrap();
+        let instruction = test_instruction(data, vec!["buffer_pubkey", "authority_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "initialize_buffer");
+        assert_eq!(property(&instruction_set, "buffer"), "buffer_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+    }
+
+    #[tokio::test]
+    async fn write_decodes_offset_and_base64_bytes() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::Write {
+            offset: 42,
+            bytes: vec![1, 2, 3, 4],
+        }).unwrap();
+        let instruction = test_instruction(data, vec!["buffer_pubkey", "authority_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "write");
+        assert_eq!(property(&instruction_set, "buffer"), "buffer_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+        assert_eq!(property(&instruction_set, "offset"), "42");
+        assert_eq!(property(&instruction_set, "bytes"), base64::encode([1, 2, 3, 4]));
+    }
+
+    #[tokio::test]
+    async fn deploy_with_max_data_len_resolves_authority_at_index_seven() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::DeployWithMaxDataLen {
+            max_data_len: 1024,
+        }).unwrap();
+        let instruction = test_instruction(data, vec![
+            "payer_pubkey",      // 0
+            "program_data_pubkey", // 1
+            "program_pubkey",    // 2
+            "buffer_pubkey",     // 3
+            "rent_sysvar",       // 4
+            "clock_sysvar",      // 5
+            "system_program",    // 6
+            "authority_pubkey",  // 7
+        ]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "deploy_with_max_data_len");
+        assert_eq!(property(&instruction_set, "payer"), "payer_pubkey");
+        assert_eq!(property(&instruction_set, "program_data"), "program_data_pubkey");
+        assert_eq!(property(&instruction_set, "program"), "program_pubkey");
+        assert_eq!(property(&instruction_set, "buffer"), "buffer_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+        assert_eq!(property(&instruction_set, "max_data_len"), "1024");
+    }
+
+    #[tokio::test]
+    async fn upgrade_resolves_authority_at_index_six() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::Upgrade).unwrap();
+        let instruction = test_instruction(data, vec![
+            "program_data_pubkey", // 0
+            "program_pubkey",      // 1
+            "buffer_pubkey",       // 2
+            "spill_pubkey",        // 3
+            "rent_sysvar",         // 4
+            "clock_sysvar",        // 5
+            "authority_pubkey",    // 6
+        ]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "upgrade");
+        assert_eq!(property(&instruction_set, "program_data"), "program_data_pubkey");
+        assert_eq!(property(&instruction_set, "program"), "program_pubkey");
+        assert_eq!(property(&instruction_set, "buffer"), "buffer_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+    }
+
+    #[tokio::test]
+    async fn set_authority_decodes_account_authority_and_new_authority() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::SetAuthority).unwrap();
+        let instruction = test_instruction(data, vec!["account_pubkey", "authority_pubkey", "new_authority_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "set_authority");
+        assert_eq!(property(&instruction_set, "account"), "account_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+        assert_eq!(property(&instruction_set, "new_authority"), "new_authority_pubkey");
+    }
+
+    #[tokio::test]
+    async fn set_authority_checked_decodes_account_authority_and_new_authority() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::SetAuthorityChecked).unwrap();
+        let instruction = test_instruction(data, vec!["account_pubkey", "authority_pubkey", "new_authority_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "set_authority_checked");
+        assert_eq!(property(&instruction_set, "account"), "account_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+        assert_eq!(property(&instruction_set, "new_authority"), "new_authority_pubkey");
+    }
+
+    #[tokio::test]
+    async fn close_decodes_account_recipient_and_authority() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::Close).unwrap();
+        let instruction = test_instruction(data, vec!["account_pubkey", "recipient_pubkey", "authority_pubkey"]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "close");
+        assert_eq!(property(&instruction_set, "account"), "account_pubkey");
+        assert_eq!(property(&instruction_set, "recipient"), "recipient_pubkey");
+        assert_eq!(property(&instruction_set, "authority"), "authority_pubkey");
+    }
+
+    #[tokio::test]
+    async fn extend_program_resolves_payer_at_index_three_skipping_system_program() {
+        let data = bincode::serialize(&UpgradeableLoaderInstruction::ExtendProgram {
+            additional_bytes: 256,
+        }).unwrap();
+        let instruction = test_instruction(data, vec![
+            "program_data_pubkey", // 0
+            "program_pubkey",      // 1
+            "system_program",      // 2 (unused)
+            "payer_pubkey",        // 3
+        ]);
+
+        let instruction_set = fragment_instruction(instruction).await.unwrap();
+
+        assert_eq!(instruction_set.function.function_name, "extend_program");
+        assert_eq!(property(&instruction_set, "program_data"), "program_data_pubkey");
+        assert_eq!(property(&instruction_set, "program"), "program_pubkey");
+        assert_eq!(property(&instruction_set, "payer"), "payer_pubkey");
+        assert_eq!(property(&instruction_set, "additional_bytes"), "256");
+    }
+
+    #[tokio::test]
+    async fn truncated_data_returns_err_with_deserialize_failure_detail() {
+        let instruction = test_instruction(vec![0xFF, 0xFF, 0xFF], vec![]);
+
+        let result = fragment_instruction(instruction).await;
+
+        let err = result.expect_err("truncated instruction data should fail to decode");
+        assert!(err.starts_with("bincode_deserialize_failed"));
+    }
+}
@@ -12,4 +12,63 @@ pub mod native_token_lending;
 pub mod native_vote;
 pub mod serum_market;
 pub mod solend;
-pub mod solend_token_lending;
\ No newline at end of file
+pub mod solend_token_lending;
+
+/// Encodes a raw byte payload (e.g. program bytecode, buffer contents) as base64 rather than
+/// a JSON integer array, which for large blobs is both far more compact and cheaper to index.
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+/// Resolves the pubkey at `index` in the instruction's account list, or an empty string if the
+/// instruction didn't include an account at that position.
+pub fn account_at(instruction: &Instruction, index: usize) -> String {
+    instruction.accounts.get(index).cloned().unwrap_or_default()
+}
+
+/// Builds a reserved "unparsed" `InstructionSet` for an instruction that either belongs to an
+/// unsupported program or whose data failed to decode, so a transaction can still be fully
+/// accounted for instead of silently dropping the instruction from the output.
+pub fn build_unparsed_instruction_set(instruction: &Instruction, error_category: &str) -> InstructionSet {
+    InstructionSet {
+        function: InstructionFunction {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            program: instruction.program.clone(),
+            function_name: "unparsed".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        properties: vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "program_id".to_string(),
+                value: instruction.program.clone(),
+                parent_key: "unparsed".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "error_category".to_string(),
+                value: error_category.to_string(),
+                parent_key: "unparsed".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "data".to_string(),
+                value: encode_bytes(&instruction.data),
+                parent_key: "unparsed".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ],
+    }
+}
\ No newline at end of file